@@ -1,6 +1,11 @@
 macro_rules! fractional_int {
     ($i:ident, $inner:ident) => {
         #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes))]
+        #[repr(transparent)]
         pub struct $i($inner);
 
         impl $i {
@@ -49,6 +54,32 @@ macro_rules! fractional_int {
             pub fn min(self, rhs: Self) -> Self {
                 Self(self.0.min(rhs.0))
             }
+
+            /// Returns the exact `(numerator, denominator)` this value represents.
+            /// For example, `FractionalU8::new(127).to_rational()` is `(127, 255)`.
+            #[inline]
+            pub fn to_rational(self) -> (u64, u64) {
+                (self.0 as u64, <$inner>::MAX as u64)
+            }
+
+            /// Rounds `num / den` into the nearest representable fraction, saturating
+            /// at `MAX` (including when `den` is zero).
+            pub fn approximate_rational(num: u32, den: u32) -> Self {
+                if den == 0 {
+                    return Self::MAX;
+                }
+                let max = <$inner>::MAX as u128;
+                let num = num as u128 * max;
+                let den = den as u128;
+                Self((((num + den / 2) / den).min(max)) as $inner)
+            }
+
+            /// Constructs a fraction like `3/4` directly from an integer ratio,
+            /// without going through float. See [`Self::approximate_rational`].
+            #[inline]
+            pub fn new_ratio(num: u32, den: u32) -> Self {
+                Self::approximate_rational(num, den)
+            }
         }
 
         impl From<$inner> for $i {
@@ -125,29 +156,504 @@ macro_rules! fractional_int {
                 Self::new(!self.0)
             }
         }
+
+        // `num_traits::One` is deliberately not implemented here: its `Mul<Self, Output
+        // = Self>` supertrait bound only holds for `FractionalU16` (see its standalone
+        // `One` impl below `FractionalU16`'s `Mul`) — `FractionalU8`'s `Mul` widens to
+        // `FractionalU16`, and `FractionalU32`/`FractionalU64` have no `Mul` at all. As a
+        // result `num_traits::Num` (which needs `Zero + One + NumOps`) isn't implemented
+        // for any type here, so generic code behind `T: Num + Bounded` isn't reachable
+        // yet; `T: Bounded` alone, or `Zero`/`ToPrimitive`/`FromPrimitive` individually,
+        // are.
+        #[cfg(feature = "num-traits")]
+        impl num_traits::Zero for $i {
+            #[inline]
+            fn zero() -> Self {
+                Self::new(0)
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl num_traits::Bounded for $i {
+            #[inline]
+            fn min_value() -> Self {
+                Self::new(0)
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl num_traits::ToPrimitive for $i {
+            #[inline]
+            fn to_i64(&self) -> Option<i64> {
+                i64::try_from(self.0 as u64).ok()
+            }
+
+            #[inline]
+            fn to_u64(&self) -> Option<u64> {
+                Some(self.0 as u64)
+            }
+
+            #[inline]
+            fn to_f32(&self) -> Option<f32> {
+                Some(Self::f32(*self))
+            }
+
+            #[inline]
+            fn to_f64(&self) -> Option<f64> {
+                Some(Self::f64(*self))
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl num_traits::FromPrimitive for $i {
+            // clamps into range, matching the saturating behavior of `new_f32`/`new_f64`
+            #[inline]
+            fn from_i64(n: i64) -> Option<Self> {
+                if n <= 0 {
+                    return Some(Self::new(0));
+                }
+                Some(Self::new((n as u64).min(<$inner>::MAX as u64) as $inner))
+            }
+
+            #[inline]
+            fn from_u64(n: u64) -> Option<Self> {
+                Some(Self::new(n.min(<$inner>::MAX as u64) as $inner))
+            }
+
+            #[inline]
+            fn from_f32(n: f32) -> Option<Self> {
+                Some(Self::new_f32(n))
+            }
+
+            #[inline]
+            fn from_f64(n: f64) -> Option<Self> {
+                Some(Self::new_f64(n))
+            }
+        }
+
+        impl Fractional for $i {
+            type Inner = $inner;
+
+            const MAX: Self = Self::MAX;
+
+            #[inline]
+            fn new(inner: Self::Inner) -> Self {
+                Self::new(inner)
+            }
+
+            #[inline]
+            fn new_f32(value: f32) -> Self {
+                Self::new_f32(value)
+            }
+
+            #[inline]
+            fn new_f64(value: f64) -> Self {
+                Self::new_f64(value)
+            }
+
+            #[inline]
+            fn f32(self) -> f32 {
+                Self::f32(self)
+            }
+
+            #[inline]
+            fn f64(self) -> f64 {
+                Self::f64(self)
+            }
+        }
     };
 }
 
+/// Abstracts over the `fractional_int!`-generated types so generic code can be
+/// written once against any precision (`FractionalU8`, `FractionalU16`, ...).
+///
+/// Widening/narrowing conversions between precisions (`FractionalU8::u16`,
+/// `FractionalU16::u8`, ...) are deliberately left out of this trait: there's
+/// no way to name "the next wider/narrower `Fractional` type" as an associated
+/// type in Rust, so those stay inherent methods on the concrete types instead.
+pub trait Fractional:
+    Sized
+    + Copy
+    + Default
+    + PartialOrd
+    + std::ops::Add<Output = Self>
+    + std::ops::Sub<Output = Self>
+    + std::ops::Not<Output = Self>
+{
+    type Inner;
+
+    const MAX: Self;
+
+    fn new(inner: Self::Inner) -> Self;
+    fn new_f32(value: f32) -> Self;
+    fn new_f64(value: f64) -> Self;
+    fn f32(self) -> f32;
+    fn f64(self) -> f64;
+}
+
 fractional_int!(FractionalU8, u8);
 fractional_int!(FractionalU16, u16);
+fractional_int!(FractionalU32, u32);
+fractional_int!(FractionalU64, u64);
+
+macro_rules! signed_fractional_int {
+    ($i:ident, $inner:ident) => {
+        #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        #[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+        #[cfg_attr(feature = "zerocopy", derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes))]
+        #[repr(transparent)]
+        pub struct $i($inner);
+
+        impl $i {
+            pub const MAX: Self = Self::new(<$inner>::MAX);
+            pub const MIN: Self = Self::new(<$inner>::MIN);
+
+            #[inline]
+            pub const fn new(value: $inner) -> Self {
+                Self(value)
+            }
+
+            // Scaled symmetrically off `MAX + 1` (the Q-format convention for signed
+            // fixed point), so `MIN` lands exactly on `-1.0` and `MAX` is just under
+            // `1.0` instead of `MAX` being exact and `MIN` overshooting past `-1.0`.
+            #[inline]
+            pub fn new_f32(value: f32) -> Self {
+                const SCALE: f32 = <$inner>::MAX as f32 + 1.0;
+                Self((value * SCALE) as $inner)
+            }
+
+            #[inline]
+            pub fn new_f64(value: f64) -> Self {
+                const SCALE: f64 = <$inner>::MAX as f64 + 1.0;
+                Self((value * SCALE) as $inner)
+            }
+
+            #[inline]
+            pub fn $inner(self) -> $inner {
+                self.0
+            }
+
+            #[inline]
+            pub fn f32(self) -> f32 {
+                const SCALE_INV: f32 = 1.0 / (<$inner>::MAX as f32 + 1.0);
+                self.0 as f32 * SCALE_INV
+            }
+
+            #[inline]
+            pub fn f64(self) -> f64 {
+                const SCALE_INV: f64 = 1.0 / (<$inner>::MAX as f64 + 1.0);
+                self.0 as f64 * SCALE_INV
+            }
+        }
+
+        impl From<$inner> for $i {
+            #[inline]
+            fn from(value: $inner) -> Self {
+                Self(value)
+            }
+        }
+
+        impl std::ops::Add for $i {
+            type Output = $i;
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0))
+            }
+        }
+
+        impl std::ops::Add<$inner> for $i {
+            type Output = $i;
+            #[inline]
+            fn add(self, rhs: $inner) -> Self {
+                Self(self.0.saturating_add(rhs))
+            }
+        }
+
+        impl std::ops::AddAssign for $i {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 = self.0.saturating_add(rhs.0);
+            }
+        }
+
+        impl std::ops::AddAssign<$inner> for $i {
+            #[inline]
+            fn add_assign(&mut self, rhs: $inner) {
+                self.0 = self.0.saturating_add(rhs);
+            }
+        }
+
+        impl std::ops::Sub for $i {
+            type Output = $i;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0))
+            }
+        }
+
+        impl std::ops::Sub<$inner> for $i {
+            type Output = $i;
+            #[inline]
+            fn sub(self, rhs: $inner) -> Self {
+                Self(self.0.saturating_sub(rhs))
+            }
+        }
+
+        impl std::ops::SubAssign for $i {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 = self.0.saturating_sub(rhs.0);
+            }
+        }
+
+        impl std::ops::SubAssign<$inner> for $i {
+            #[inline]
+            fn sub_assign(&mut self, rhs: $inner) {
+                self.0 = self.0.saturating_sub(rhs);
+            }
+        }
+
+        impl std::ops::Neg for $i {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                Self(self.0.saturating_neg())
+            }
+        }
+    };
+}
+
+signed_fractional_int!(FractionalI8, i8);
+signed_fractional_int!(FractionalI16, i16);
 
 impl FractionalU8 {
     pub fn u16(self) -> FractionalU16 {
         FractionalU16::new(self.0 as u16 * 257)
     }
+
+    /// Quantizes a whole buffer of normalized `f32` samples at once, applying the
+    /// same clamping/scaling as [`Self::new_f32`]. Panics if the slices differ in length.
+    pub fn from_f32_slice(src: &[f32], dst: &mut [Self]) {
+        assert_eq!(src.len(), dst.len());
+        const MAX: f32 = u8::MAX as f32;
+        for (src, dst) in src.iter().zip(dst.iter_mut()) {
+            *dst = Self((*src * MAX) as u8);
+        }
+    }
+
+    /// Widens a whole buffer back out to `f32`, the inverse of [`Self::from_f32_slice`].
+    /// Panics if the slices differ in length.
+    pub fn to_f32_slice(src: &[Self], dst: &mut [f32]) {
+        assert_eq!(src.len(), dst.len());
+        const MAX_INV: f32 = 1.0 / u8::MAX as f32;
+        for (src, dst) in src.iter().zip(dst.iter_mut()) {
+            *dst = src.0 as f32 * MAX_INV;
+        }
+    }
+
+    /// Widens a whole buffer back out to `f64`, the inverse of [`Self::from_f32_slice`].
+    /// Panics if the slices differ in length.
+    pub fn to_f64_slice(src: &[Self], dst: &mut [f64]) {
+        assert_eq!(src.len(), dst.len());
+        const MAX_INV: f64 = 1.0 / u8::MAX as f64;
+        for (src, dst) in src.iter().zip(dst.iter_mut()) {
+            *dst = src.0 as f64 * MAX_INV;
+        }
+    }
+
+    /// `Vec`-returning convenience wrapper around [`Self::from_f32_slice`].
+    pub fn from_f32_vec(src: &[f32]) -> Vec<Self> {
+        let mut dst = vec![Self::default(); src.len()];
+        Self::from_f32_slice(src, &mut dst);
+        dst
+    }
+
+    /// `Vec`-returning convenience wrapper around [`Self::to_f32_slice`].
+    pub fn to_f32_vec(src: &[Self]) -> Vec<f32> {
+        let mut dst = vec![0.0; src.len()];
+        Self::to_f32_slice(src, &mut dst);
+        dst
+    }
+
+    /// `Vec`-returning convenience wrapper around [`Self::to_f64_slice`].
+    pub fn to_f64_vec(src: &[Self]) -> Vec<f64> {
+        let mut dst = vec![0.0; src.len()];
+        Self::to_f64_slice(src, &mut dst);
+        dst
+    }
+
+    /// Linearly interpolates from `self` to `other` by `t`, computing
+    /// `self * (1 - t) + other * t` via the exact widened-integer path.
+    pub fn lerp(self, other: Self, t: Self) -> Self {
+        let not_t = !t;
+        let num = self.0 as u32 * not_t.0 as u32 + other.0 as u32 * t.0 as u32;
+        const MAX: u32 = u8::MAX as u32;
+        Self(((num + MAX / 2) / MAX) as u8)
+    }
+
+    /// Source-over compositing: `self` painted over `other` at the given `alpha`,
+    /// i.e. `self * alpha + other * (1 - alpha)`.
+    pub fn over(self, other: Self, alpha: Self) -> Self {
+        other.lerp(self, alpha)
+    }
 }
 
 impl FractionalU16 {
     pub fn u8(self) -> FractionalU8 {
         FractionalU8::new((self.0 / 257) as u8)
     }
+
+    pub fn u32(self) -> FractionalU32 {
+        FractionalU32::new(self.0 as u32 * 65537)
+    }
+}
+
+impl FractionalU32 {
+    pub fn u16(self) -> FractionalU16 {
+        FractionalU16::new((self.0 / 65537) as u16)
+    }
+
+    pub fn u64(self) -> FractionalU64 {
+        FractionalU64::new(self.0 as u64 * 4_294_967_297)
+    }
+}
+
+impl FractionalU64 {
+    pub fn u32(self) -> FractionalU32 {
+        FractionalU32::new((self.0 / 4_294_967_297) as u32)
+    }
+}
+
+impl FractionalI8 {
+    /// Converts the non-negative sub-range shared with `FractionalU8` (`0.0..=1.0`),
+    /// returning `None` for negative values. Rescales exactly via widened-integer
+    /// arithmetic (round-to-nearest, see [`FractionalU8::approximate_rational`]),
+    /// not a lossy float round-trip; still not bijective with [`FractionalU8::to_i8`]
+    /// on every value, since the two types don't share a common denominator.
+    pub fn to_u8(self) -> Option<FractionalU8> {
+        if self.0 < 0 {
+            return None;
+        }
+        const SCALE: u32 = i8::MAX as u32 + 1; // matches new_f32's Q-format scaling
+        const MAX: u32 = u8::MAX as u32;
+        let num = self.0 as u32 * MAX;
+        Some(FractionalU8(((num + SCALE / 2) / SCALE).min(MAX) as u8))
+    }
+}
+
+impl FractionalU8 {
+    /// Rescales exactly via widened-integer arithmetic (round-to-nearest), saturating
+    /// at [`FractionalI8::MAX`] rather than overflowing, since the full `FractionalU8`
+    /// range is slightly wider than `FractionalI8`'s `0.0..=1.0` sub-range.
+    pub fn to_i8(self) -> FractionalI8 {
+        const SCALE: u32 = i8::MAX as u32 + 1;
+        const MAX: u32 = u8::MAX as u32;
+        let num = self.0 as u32 * SCALE;
+        FractionalI8((((num + MAX / 2) / MAX).min(i8::MAX as u32)) as i8)
+    }
+}
+
+impl FractionalI16 {
+    /// Converts the non-negative sub-range shared with `FractionalU16` (`0.0..=1.0`),
+    /// returning `None` for negative values. Rescales exactly via widened-integer
+    /// arithmetic (round-to-nearest, see [`FractionalU16::approximate_rational`]),
+    /// not a lossy float round-trip; still not bijective with [`FractionalU16::to_i16`]
+    /// on every value, since the two types don't share a common denominator.
+    pub fn to_u16(self) -> Option<FractionalU16> {
+        if self.0 < 0 {
+            return None;
+        }
+        const SCALE: u64 = i16::MAX as u64 + 1; // matches new_f32's Q-format scaling
+        const MAX: u64 = u16::MAX as u64;
+        let num = self.0 as u64 * MAX;
+        Some(FractionalU16(((num + SCALE / 2) / SCALE).min(MAX) as u16))
+    }
+}
+
+impl FractionalU16 {
+    /// Rescales exactly via widened-integer arithmetic (round-to-nearest), saturating
+    /// at [`FractionalI16::MAX`] rather than overflowing, since the full `FractionalU16`
+    /// range is slightly wider than `FractionalI16`'s `0.0..=1.0` sub-range.
+    pub fn to_i16(self) -> FractionalI16 {
+        const SCALE: u64 = i16::MAX as u64 + 1;
+        const MAX: u64 = u16::MAX as u64;
+        let num = self.0 as u64 * SCALE;
+        FractionalI16((((num + MAX / 2) / MAX).min(i16::MAX as u64)) as i16)
+    }
 }
 
 impl std::ops::Mul for FractionalU8 {
     type Output = FractionalU16;
 
+    /// Exact widened-integer multiplication: `a/255 * b/255 == num/65025`, rescaled
+    /// to a `FractionalU16`'s `65535` denominator and rounded to the nearest value.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let num = self.0 as u32 * rhs.0 as u32 * u16::MAX as u32;
+        const DEN: u32 = u8::MAX as u32 * u8::MAX as u32;
+        FractionalU16::new(((num + DEN / 2) / DEN) as u16)
+    }
+}
+
+impl std::ops::Div for FractionalU8 {
+    type Output = Self;
+
+    /// Exact widened-integer division, rounded to the nearest representable value.
+    /// Saturates to `MAX` when `rhs` is zero or the ratio is `>= 1.0`.
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.0 == 0 || self.0 >= rhs.0 {
+            return Self::MAX;
+        }
+        let num = self.0 as u16 * u8::MAX as u16;
+        let den = rhs.0 as u16;
+        Self::new(((num + den / 2) / den) as u8)
+    }
+}
+
+impl std::ops::Mul for FractionalU16 {
+    type Output = Self;
+
+    /// Exact widened-integer multiplication, rounded to the nearest representable value.
     fn mul(self, rhs: Self) -> Self::Output {
-        FractionalU16::new_f64(self.f64() * rhs.f64())
+        let num = self.0 as u32 * rhs.0 as u32;
+        const MAX: u32 = u16::MAX as u32;
+        Self::new(((num + MAX / 2) / MAX) as u16)
+    }
+}
+
+// See the note above `impl num_traits::Zero for $i` in the `fractional_int!` macro:
+// `FractionalU16` is the only generated type whose `Mul` satisfies `One`'s bound.
+#[cfg(feature = "num-traits")]
+impl num_traits::One for FractionalU16 {
+    #[inline]
+    fn one() -> Self {
+        Self::MAX
+    }
+}
+
+impl std::ops::Div for FractionalU16 {
+    type Output = Self;
+
+    /// Exact widened-integer division, rounded to the nearest representable value.
+    /// Saturates to `MAX` when `rhs` is zero or the ratio is `>= 1.0`.
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.0 == 0 || self.0 >= rhs.0 {
+            return Self::MAX;
+        }
+        let num = self.0 as u32 * u16::MAX as u32;
+        let den = rhs.0 as u32;
+        Self::new(((num + den / 2) / den) as u16)
     }
 }
 
@@ -235,6 +741,52 @@ mod test {
         assert_eq!(u8::MAX, one.u8().0);
     }
 
+    #[test]
+    fn u16_to_u32() {
+        let zero = FractionalU16::new(0);
+        let one = FractionalU16::new(u16::MAX);
+
+        assert_eq!(0, zero.u32().0);
+        assert_eq!(u32::MAX, one.u32().0);
+    }
+
+    #[test]
+    fn u32_to_u16() {
+        let zero = FractionalU32::new(0);
+        let one = FractionalU32::new(u32::MAX);
+
+        assert_eq!(0, zero.u16().0);
+        assert_eq!(u16::MAX, one.u16().0);
+    }
+
+    #[test]
+    fn u32_to_u64() {
+        let zero = FractionalU32::new(0);
+        let one = FractionalU32::new(u32::MAX);
+
+        assert_eq!(0, zero.u64().0);
+        assert_eq!(u64::MAX, one.u64().0);
+    }
+
+    #[test]
+    fn u64_to_u32() {
+        let zero = FractionalU64::new(0);
+        let one = FractionalU64::new(u64::MAX);
+
+        assert_eq!(0, zero.u32().0);
+        assert_eq!(u32::MAX, one.u32().0);
+    }
+
+    #[test]
+    fn fractional_trait_is_generic_over_precision() {
+        fn half<T: Fractional>() -> T {
+            T::new_f64(0.5)
+        }
+
+        assert_eq!(FractionalU8::new_f64(0.5), half::<FractionalU8>());
+        assert_eq!(FractionalU64::new_f64(0.5), half::<FractionalU64>());
+    }
+
     #[test]
     fn u8_mul_to_u16() {
         assert_eq!(
@@ -258,11 +810,49 @@ mod test {
         );
 
         assert_eq!(
-            FractionalU16::new_f32(0.24805), // rounding error
+            FractionalU16::new(16256), // exact: round(127 * 127 * 65535 / 65025)
             FractionalU8::new_f32(0.5) * FractionalU8::new_f32(0.5)
         );
     }
 
+    #[test]
+    fn u8_div() {
+        assert_eq!(FractionalU8::MAX, FractionalU8::new(1) / FractionalU8::new(0));
+        assert_eq!(FractionalU8::MAX, FractionalU8::new(200) / FractionalU8::new(100));
+        assert_eq!(FractionalU8::MAX, FractionalU8::new(100) / FractionalU8::new(100));
+        assert_eq!(FractionalU8::new(128), FractionalU8::new(100) / FractionalU8::new(200));
+        assert_eq!(FractionalU8::new(0), FractionalU8::new(0) / FractionalU8::new(100));
+    }
+
+    #[test]
+    fn u16_mul() {
+        assert_eq!(
+            FractionalU16::new(0),
+            FractionalU16::new(0) * FractionalU16::new(u16::MAX)
+        );
+        assert_eq!(
+            FractionalU16::MAX,
+            FractionalU16::MAX * FractionalU16::MAX
+        );
+        assert_eq!(
+            FractionalU16::new(u16::MAX / 2),
+            FractionalU16::new(u16::MAX / 2) * FractionalU16::MAX
+        );
+    }
+
+    #[test]
+    fn u16_div() {
+        assert_eq!(FractionalU16::MAX, FractionalU16::new(1) / FractionalU16::new(0));
+        assert_eq!(
+            FractionalU16::MAX,
+            FractionalU16::new(40000) / FractionalU16::new(20000)
+        );
+        assert_eq!(
+            FractionalU16::new(0),
+            FractionalU16::new(0) / FractionalU16::new(20000)
+        );
+    }
+
     #[test]
     fn not() {
         use std::ops::Not;
@@ -271,4 +861,208 @@ mod test {
         assert_eq!(255, FractionalU8::new(0).not().u8());
         assert_eq!(55, FractionalU8::new(200).not().u8());
     }
+
+    #[test]
+    fn f32_slice_matches_scalar() {
+        let samples = [0.0, 0.25, 0.5, 0.75, 1.0];
+
+        let fractions = FractionalU8::from_f32_vec(&samples);
+        let expected: Vec<_> = samples.iter().map(|f| FractionalU8::new_f32(*f)).collect();
+        assert_eq!(fractions, expected);
+
+        let widened = FractionalU8::to_f32_vec(&fractions);
+        let expected: Vec<_> = fractions.iter().map(|f| f.f32()).collect();
+        assert_eq!(widened, expected);
+    }
+
+    #[test]
+    fn f64_slice() {
+        let fractions = [FractionalU8::new(0), FractionalU8::MAX];
+
+        assert_eq!(FractionalU8::to_f64_vec(&fractions), vec![0.0, 1.0]);
+    }
+
+    #[test]
+    fn signed_f32_round_trip() {
+        assert_eq!(0.0, FractionalI8::new(0).f32());
+        assert_eq!(-1.0, FractionalI8::new_f32(-1.0).f32());
+
+        assert_eq!(FractionalI8::MAX, FractionalI8::new_f32(1.1));
+        assert_eq!(FractionalI8::MIN, FractionalI8::new_f32(-1.1));
+    }
+
+    #[test]
+    fn signed_min_is_exactly_minus_one() {
+        // `MIN` is the actual minimum of the type and must land exactly on -1.0;
+        // `MAX` is one step short of 1.0, since there's no symmetric positive
+        // counterpart to `MIN` in two's complement.
+        assert_eq!(-1.0, FractionalI8::MIN.f32());
+        assert!(FractionalI8::MAX.f32() < 1.0);
+
+        assert_eq!(-1.0, FractionalI16::MIN.f32());
+        assert!(FractionalI16::MAX.f32() < 1.0);
+    }
+
+    #[test]
+    fn signed_saturating_ops() {
+        assert_eq!(FractionalI8::MAX, FractionalI8::MAX + FractionalI8::MAX);
+        assert_eq!(FractionalI8::MIN, FractionalI8::MIN - FractionalI8::MAX);
+        assert_eq!(FractionalI8::MAX, -FractionalI8::MIN);
+    }
+
+    #[test]
+    fn signed_unsigned_conversion() {
+        assert_eq!(None, FractionalI8::new(-1).to_u8());
+        assert_eq!(Some(FractionalU8::new(0)), FractionalI8::new(0).to_u8());
+        // `FractionalI8::MAX` is just under 1.0, so it narrows to just under
+        // `FractionalU8::MAX` rather than landing on it exactly.
+        assert_eq!(Some(FractionalU8::new(253)), FractionalI8::MAX.to_u8());
+
+        assert_eq!(FractionalI8::new(0), FractionalU8::new(0).to_i8());
+        assert_eq!(FractionalI8::MAX, FractionalU8::MAX.to_i8());
+
+        assert_eq!(None, FractionalI16::new(-1).to_u16());
+        assert_eq!(Some(FractionalU16::new(65533)), FractionalI16::MAX.to_u16());
+        assert_eq!(FractionalI16::MAX, FractionalU16::MAX.to_i16());
+    }
+
+    #[test]
+    fn lerp_at_endpoints() {
+        let a = FractionalU8::new(100);
+        let b = FractionalU8::new(200);
+
+        assert_eq!(a, a.lerp(b, FractionalU8::new(0)));
+        assert_eq!(b, a.lerp(b, FractionalU8::MAX));
+    }
+
+    #[test]
+    fn lerp_midpoint() {
+        let black = FractionalU8::new(0);
+        let white = FractionalU8::MAX;
+
+        assert_eq!(FractionalU8::new(127), black.lerp(white, FractionalU8::new(127)));
+    }
+
+    #[test]
+    fn over_compositing() {
+        let fg = FractionalU8::new(100);
+        let bg = FractionalU8::new(200);
+
+        // fully opaque foreground wins
+        assert_eq!(fg, fg.over(bg, FractionalU8::MAX));
+        // fully transparent foreground leaves the background untouched
+        assert_eq!(bg, fg.over(bg, FractionalU8::new(0)));
+    }
+
+    #[test]
+    fn to_rational() {
+        assert_eq!((0, 255), FractionalU8::new(0).to_rational());
+        assert_eq!((255, 255), FractionalU8::MAX.to_rational());
+        assert_eq!((127, 255), FractionalU8::new(127).to_rational());
+    }
+
+    #[test]
+    fn to_rational_wide_types_do_not_truncate() {
+        assert_eq!(
+            (0, u32::MAX as u64),
+            FractionalU32::new(0).to_rational()
+        );
+        assert_eq!(
+            (u32::MAX as u64, u32::MAX as u64),
+            FractionalU32::MAX.to_rational()
+        );
+        assert_eq!(
+            (1_000_000_000_000, u64::MAX),
+            FractionalU64::new(1_000_000_000_000).to_rational()
+        );
+        assert_eq!((u64::MAX, u64::MAX), FractionalU64::MAX.to_rational());
+    }
+
+    #[test]
+    fn approximate_rational() {
+        assert_eq!(FractionalU8::MAX, FractionalU8::approximate_rational(1, 1));
+        assert_eq!(FractionalU8::new(0), FractionalU8::approximate_rational(0, 4));
+        assert_eq!(
+            FractionalU8::new(191),
+            FractionalU8::approximate_rational(3, 4)
+        );
+        // saturates rather than panicking on division by zero
+        assert_eq!(FractionalU8::MAX, FractionalU8::approximate_rational(1, 0));
+    }
+
+    #[test]
+    fn new_ratio() {
+        assert_eq!(
+            FractionalU8::approximate_rational(3, 4),
+            FractionalU8::new_ratio(3, 4)
+        );
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_traits_zero_and_bounded() {
+        use num_traits::{Bounded, Zero};
+
+        assert_eq!(FractionalU8::new(0), FractionalU8::zero());
+        assert!(FractionalU8::new(0).is_zero());
+        assert!(!FractionalU8::MAX.is_zero());
+
+        assert_eq!(FractionalU8::new(0), FractionalU8::min_value());
+        assert_eq!(FractionalU8::MAX, FractionalU8::max_value());
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_traits_one_on_u16() {
+        use num_traits::One;
+
+        assert_eq!(FractionalU16::MAX, FractionalU16::one());
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn num_traits_to_and_from_primitive() {
+        use num_traits::{FromPrimitive, ToPrimitive};
+
+        assert_eq!(Some(1.0), FractionalU8::MAX.to_f64());
+        assert_eq!(Some(0), FractionalU8::new(0).to_i64());
+        assert_eq!(Some(255), FractionalU8::MAX.to_u64());
+
+        assert_eq!(Some(FractionalU8::MAX), FractionalU8::from_f64(1.0));
+        assert_eq!(Some(FractionalU8::new(0)), FractionalU8::from_i64(-5));
+        assert_eq!(Some(FractionalU8::MAX), FractionalU8::from_i64(1000));
+        assert_eq!(Some(FractionalU8::MAX), FractionalU8::from_u64(1000));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let value = FractionalU8::new(200);
+        let json = serde_json::to_string(&value).unwrap();
+
+        // serializes as the compact inner integer, not a wrapped struct
+        assert_eq!("200", json);
+        assert_eq!(value, serde_json::from_str(&json).unwrap());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_cast_slice() {
+        let values = [FractionalU8::new(1), FractionalU8::new(2), FractionalU8::new(3)];
+        let bytes: &[u8] = bytemuck::cast_slice(&values);
+
+        assert_eq!(&[1, 2, 3], bytes);
+        assert_eq!(&values, bytemuck::cast_slice::<u8, FractionalU8>(bytes));
+    }
+
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn zerocopy_as_and_from_bytes() {
+        use zerocopy::{AsBytes, FromBytes};
+
+        let value = FractionalU16::new(12345);
+        let bytes = value.as_bytes();
+
+        assert_eq!(value, FractionalU16::read_from(bytes).unwrap());
+    }
 }